@@ -0,0 +1,40 @@
+//! Parsed DNS names.
+//!
+//! Unlike [`domain`](crate::domain) names, DNS names may be fully qualified
+//! (end in a trailing `.`) and may contain any octet in a label, not just
+//! alphanumerics and hyphens.
+
+use crate::Suffix;
+
+/// A DNS name that has been validated and matched against a suffix list.
+///
+/// Build one with [`DnsName::parse_dns_name`](crate::parser::DnsName::parse_dns_name).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Name<'a> {
+    full: &'a str,
+    suffix: Option<Suffix<'a>>,
+}
+
+impl<'a> Name<'a> {
+    pub(crate) fn new(full: &'a str, suffix: Option<Suffix<'a>>) -> Self {
+        Name { full, suffix }
+    }
+
+    /// The DNS name as given to the parser.
+    pub fn as_str(&self) -> &'a str {
+        self.full
+    }
+
+    /// The suffix of this name, or `None` if no rule in the suffix list
+    /// matches it (e.g. the root name, `"."`, which has no labels). Unlike
+    /// [`domain::Name::suffix`](crate::domain::Name::suffix), there is no
+    /// fallback to the rightmost label.
+    pub fn suffix(&self) -> Option<Suffix<'a>> {
+        self.suffix
+    }
+
+    /// Whether this name is fully qualified, i.e. ends in a `.`.
+    pub fn is_fqdn(&self) -> bool {
+        self.full.ends_with('.')
+    }
+}
@@ -0,0 +1,52 @@
+//! Parsed domain names, i.e. RFC 1123 hostnames.
+
+use crate::{matcher, Suffix};
+
+/// A domain name that has been validated and matched against a suffix
+/// list.
+///
+/// Build one with [`DomainName::parse_domain_name`](crate::parser::DomainName::parse_domain_name).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Name<'a> {
+    full: &'a str,
+    suffix: Suffix<'a>,
+    suffix_len: usize,
+}
+
+impl<'a> Name<'a> {
+    pub(crate) fn new(full: &'a str, suffix: Suffix<'a>, suffix_len: usize) -> Self {
+        Name {
+            full,
+            suffix,
+            suffix_len,
+        }
+    }
+
+    /// The domain name as given to the parser.
+    pub fn as_str(&self) -> &'a str {
+        self.full
+    }
+
+    /// The suffix of this domain name, e.g. `com` or `co.uk`.
+    pub fn suffix(&self) -> Suffix<'a> {
+        self.suffix
+    }
+
+    /// The registrable part of this domain name, i.e. the suffix plus the
+    /// label immediately to its left, or `None` if the domain name is
+    /// itself just the suffix.
+    pub fn root(&self) -> Option<&'a str> {
+        let total_labels = matcher::label_count(self.full);
+        if total_labels <= self.suffix_len {
+            return None;
+        }
+        Some(matcher::label_suffix(self.full, self.suffix_len + 1))
+    }
+
+    /// Whether the suffix of this domain name is a rule found in the
+    /// Public Suffix List, as opposed to an unrecognised, unverifiable
+    /// fallback of just the rightmost label.
+    pub fn has_known_suffix(&self) -> bool {
+        self.suffix.typ().is_some()
+    }
+}
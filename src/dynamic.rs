@@ -0,0 +1,158 @@
+//! An optional, runtime-updatable Public Suffix List.
+//!
+//! The compiled [`List`](crate::List) is a fixed snapshot, so picking up
+//! registry changes (new gTLDs, new private entries) normally means
+//! recompiling and redeploying. Enabling the `dynamic` feature adds a
+//! [`List`] here that parses a `public_suffix_list.dat`-formatted byte
+//! buffer at runtime instead, implementing the same
+//! [`parser::DomainName`](crate::parser::DomainName) and
+//! [`parser::DnsName`](crate::parser::DnsName) traits. Fetching and caching
+//! the bytes (from disk, a CDN, or the registry's own copy) is left to the
+//! caller.
+//!
+//! ```
+//! use psl::{dynamic::List, parser::DomainName};
+//!
+//! let list = List::new(b"com\nexample.com\n");
+//! let domain = list.parse_domain_name("www.example.com").unwrap();
+//! assert_eq!(domain.suffix(), "example.com");
+//! ```
+
+use crate::matcher::{Match, Matcher};
+use crate::Type;
+
+use alloc::{string::String, string::ToString, vec::Vec};
+
+/// A Public Suffix List parsed from a `.dat` buffer at runtime.
+#[derive(Clone, Debug, Default)]
+pub struct List {
+    rules: Vec<Rule>,
+}
+
+#[derive(Clone, Debug)]
+struct Rule {
+    /// The rule's labels, left to right and lower-cased, as written in the
+    /// list. A wildcard label (from a leading `*.`) is stored as `"*"`.
+    labels: Vec<String>,
+    /// Whether this is a `!`-prefixed exception rule.
+    exception: bool,
+    typ: Type,
+}
+
+impl List {
+    /// Parses `data`, the contents of a `public_suffix_list.dat`-formatted
+    /// buffer, into a `List`.
+    ///
+    /// Lines up to (but not including) `// ===BEGIN PRIVATE DOMAINS===` are
+    /// tagged [`Type::Icann`]; that marker and everything after it are
+    /// tagged [`Type::Private`]. Blank lines and comments are ignored.
+    pub fn new(data: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(data);
+        let mut rules = Vec::new();
+        let mut typ = Type::Icann;
+        for line in text.lines() {
+            let line = line.trim();
+            if line.starts_with("// ===BEGIN PRIVATE DOMAINS===") {
+                typ = Type::Private;
+                continue;
+            }
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            let (exception, rule) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+            let labels = rule
+                .to_ascii_lowercase()
+                .split('.')
+                .map(ToString::to_string)
+                .collect();
+            rules.push(Rule {
+                labels,
+                exception,
+                typ,
+            });
+        }
+        List { rules }
+    }
+}
+
+impl Matcher for List {
+    fn find(&self, domain: &str) -> Option<Match> {
+        let labels: Vec<&str> = domain.split('.').collect();
+        let mut best: Option<&Rule> = None;
+
+        // The prevailing rule is whichever matching rule has the most
+        // labels, comparing a rule's labels with the domain's rightmost
+        // labels (a `*` label matches any label). A longer rule always
+        // beats a shorter one, regardless of whether either is an
+        // exception; at equal length an exception beats a non-exception,
+        // and otherwise the rule listed first is kept.
+        for rule in &self.rules {
+            if rule.labels.len() > labels.len() {
+                continue;
+            }
+            let start = labels.len() - rule.labels.len();
+            let matched = rule
+                .labels
+                .iter()
+                .zip(&labels[start..])
+                .all(|(r, d)| r == "*" || r == *d);
+            if matched && best.is_none_or(|b| more_specific(rule, b)) {
+                best = Some(rule);
+            }
+        }
+
+        best.map(|rule| {
+            // An exception rule's prevailing suffix is the rule itself
+            // with its leftmost label removed, e.g. `!city.kawasaki.jp`
+            // means `city.kawasaki.jp` is registrable even though
+            // `*.kawasaki.jp` otherwise matches.
+            let len = if rule.exception {
+                rule.labels.len() - 1
+            } else {
+                rule.labels.len()
+            };
+            Match { len, typ: rule.typ }
+        })
+    }
+}
+
+/// Whether `candidate` should replace `current` as the prevailing rule: a
+/// rule with more labels is always more specific, and at equal label
+/// count an exception rule wins, since the PSL format relies on an
+/// exception being able to override a same-length wildcard regardless of
+/// which of the two appears first in the list.
+fn more_specific(candidate: &Rule, current: &Rule) -> bool {
+    match candidate.labels.len().cmp(&current.labels.len()) {
+        core::cmp::Ordering::Greater => true,
+        core::cmp::Ordering::Equal => candidate.exception && !current.exception,
+        core::cmp::Ordering::Less => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::DomainName;
+
+    #[test]
+    fn exception_overrides_same_length_wildcard_regardless_of_order() {
+        let list = List::new(b"jp\n*.kawasaki.jp\n!city.kawasaki.jp\n");
+        let domain = list.parse_domain_name("a.city.kawasaki.jp").unwrap();
+        assert_eq!(domain.suffix(), "kawasaki.jp");
+        assert_eq!(domain.root(), Some("city.kawasaki.jp"));
+
+        // A sibling under the wildcard that isn't excepted still gets the
+        // full wildcard-matched suffix.
+        let domain = list.parse_domain_name("a.b.kawasaki.jp").unwrap();
+        assert_eq!(domain.suffix(), "b.kawasaki.jp");
+
+        // The same holds with the exception listed before the wildcard.
+        let list = List::new(b"jp\n!city.kawasaki.jp\n*.kawasaki.jp\n");
+        let domain = list.parse_domain_name("a.city.kawasaki.jp").unwrap();
+        assert_eq!(domain.suffix(), "kawasaki.jp");
+        assert_eq!(domain.root(), Some("city.kawasaki.jp"));
+    }
+}
@@ -6,8 +6,8 @@
   ## Examples
 
   ```rust
-  # fn main() -> addr::Result<()> {
-  use addr::parser::{DomainName, DnsName};
+  # fn main() -> psl::Result<'static, ()> {
+  use psl::parser::{DomainName, DnsName};
   use psl::List;
 
   // You can find out the root domain
@@ -29,7 +29,7 @@
   assert_eq!(domain.suffix(), "uk.com");
 
   let name = List.parse_dns_name("_tcp.example.com.")?;
-  assert_eq!(name.suffix(), Some("com."));
+  assert_eq!(name.suffix().unwrap(), "com.");
 
   // In any case if the domain's suffix is in the list
   // then this is definately a registrable domain name
@@ -42,10 +42,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 
+extern crate alloc;
+
 pub mod dns;
 pub mod domain;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
 #[cfg(any(feature = "net", feature = "serde-net"))]
 pub mod email;
+mod list;
 mod matcher;
 #[cfg(any(feature = "net", feature = "serde-net"))]
 pub mod net;
@@ -53,15 +58,85 @@ pub mod parser;
 #[cfg(any(feature = "serde-psl", feature = "serde-net"))]
 mod serde;
 
+pub use list::List;
+
 use core::fmt;
 
 /// Custom result type
-pub type Result<T> = core::result::Result<T, Error>;
+pub type Result<'a, T> = core::result::Result<T, Error<'a>>;
+
+/// Which section of the Public Suffix List a matched suffix came from.
+///
+/// The list is split into a section of genuine registry TLDs (ICANN
+/// domains) and a section of suffixes delegated to a private operator
+/// (the `// ===BEGIN PRIVATE DOMAINS===` section), such as `github.io` or
+/// `s3.amazonaws.com`. Consumers that need to treat the two differently
+/// (e.g. to decide whether a cookie boundary is operator- or
+/// registry-controlled) can inspect this via [`Suffix::typ`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Type {
+    Icann,
+    Private,
+}
+
+/// A matched suffix, together with which section of the list it came
+/// from, if any.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Suffix<'a> {
+    name: &'a str,
+    typ: Option<Type>,
+}
+
+impl<'a> Suffix<'a> {
+    pub(crate) fn new(name: &'a str, typ: Option<Type>) -> Self {
+        Suffix { name, typ }
+    }
+
+    /// The suffix as a string slice.
+    pub fn as_str(&self) -> &'a str {
+        self.name
+    }
+
+    /// The section of the Public Suffix List the matched rule came from,
+    /// or `None` if there was no matching rule and the suffix is simply
+    /// the rightmost label.
+    pub fn typ(&self) -> Option<Type> {
+        self.typ
+    }
+}
+
+impl<'a> fmt::Display for Suffix<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
 
-/// The errors returned by this crate
+impl<'a> AsRef<str> for Suffix<'a> {
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+impl<'a> PartialEq<str> for Suffix<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.name == other
+    }
+}
+
+impl<'a> PartialEq<&str> for Suffix<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self.name == *other
+    }
+}
+
+/// The kinds of errors returned by this crate.
+///
+/// This used to be the error type itself; it is now reachable from the
+/// richer [`Error`] via [`Error::kind`], so `match`es against it keep
+/// working unchanged.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[non_exhaustive]
-pub enum Error {
+pub enum ErrorKind {
     NameTooLong,
     EmptyLabel,
     EmailLocalTooLong,
@@ -70,6 +145,9 @@ pub enum Error {
     IllegalCharacter,
     InvalidDomain,
     InvalidIpAddr,
+    /// Returned only when the `strict-tld` feature is enabled: the TLD is
+    /// neither entirely ASCII letters nor a valid A-label.
+    InvalidTld,
     LabelEndNotAlnum,
     LabelStartNotAlnum,
     LabelTooLong,
@@ -81,30 +159,86 @@ pub enum Error {
     TooManyLabels,
 }
 
-impl fmt::Display for Error {
+impl fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let error = match self {
-            Error::NameTooLong => "name too long",
-            Error::EmptyLabel => "domain/email contains empty label",
-            Error::EmailLocalTooLong => "email local too long",
-            Error::EmailTooLong => "email too long",
-            Error::EmptyName => "name is empty",
-            Error::IllegalCharacter => "domain contains illegal characters",
-            Error::InvalidDomain => "invalid domain name",
-            Error::InvalidIpAddr => "email has an invalid ip address",
-            Error::LabelEndNotAlnum => "label does not start with an alphanumeric character",
-            Error::LabelStartNotAlnum => "label does not end with a alphanumeric character",
-            Error::LabelTooLong => "label too long",
-            Error::NoAtSign => "email address has no at sign",
-            Error::NoHostPart => "email address has no host part",
-            Error::NoUserPart => "email address has no user part",
-            Error::NumericTld => "numeric TLD",
-            Error::QuoteUnclosed => "email has an unclosed quotation mark",
-            Error::TooManyLabels => "too many labels",
+            ErrorKind::NameTooLong => "name too long",
+            ErrorKind::EmptyLabel => "domain/email contains empty label",
+            ErrorKind::EmailLocalTooLong => "email local too long",
+            ErrorKind::EmailTooLong => "email too long",
+            ErrorKind::EmptyName => "name is empty",
+            ErrorKind::IllegalCharacter => "domain contains illegal characters",
+            ErrorKind::InvalidDomain => "invalid domain name",
+            ErrorKind::InvalidIpAddr => "email has an invalid ip address",
+            ErrorKind::InvalidTld => "invalid TLD",
+            ErrorKind::LabelEndNotAlnum => "label does not end with an alphanumeric character",
+            ErrorKind::LabelStartNotAlnum => "label does not start with an alphanumeric character",
+            ErrorKind::LabelTooLong => "label too long",
+            ErrorKind::NoAtSign => "email address has no at sign",
+            ErrorKind::NoHostPart => "email address has no host part",
+            ErrorKind::NoUserPart => "email address has no user part",
+            ErrorKind::NumericTld => "numeric TLD",
+            ErrorKind::QuoteUnclosed => "email has an unclosed quotation mark",
+            ErrorKind::TooManyLabels => "too many labels",
         };
         write!(f, "{}", error)
     }
 }
 
+/// An error produced while parsing a name, together with the input that
+/// triggered it.
+///
+/// Batch jobs that parse many names can log `input()` (and `label_offset()`,
+/// when the failure is specific to one label) without having to re-derive
+/// which input or label was at fault. Existing code that matches on the
+/// error kind can migrate by matching on [`Error::kind`] instead.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Error<'a> {
+    kind: ErrorKind,
+    input: &'a str,
+    label_offset: Option<usize>,
+}
+
+impl<'a> Error<'a> {
+    pub(crate) fn new(kind: ErrorKind, input: &'a str) -> Self {
+        Error {
+            kind,
+            input,
+            label_offset: None,
+        }
+    }
+
+    pub(crate) fn at(kind: ErrorKind, input: &'a str, label_offset: usize) -> Self {
+        Error {
+            kind,
+            input,
+            label_offset: Some(label_offset),
+        }
+    }
+
+    /// The kind of error that occurred.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The input that failed to parse.
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// The byte offset, into `input()`, of the label that triggered the
+    /// error, if the error is specific to one label rather than the name
+    /// as a whole.
+    pub fn label_offset(&self) -> Option<usize> {
+        self.label_offset
+    }
+}
+
+impl<'a> fmt::Display for Error<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (in {:?})", self.kind, self.input)
+    }
+}
+
 #[cfg(feature = "std")]
-impl std::error::Error for Error {}
+impl<'a> std::error::Error for Error<'a> {}
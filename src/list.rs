@@ -0,0 +1,88 @@
+//! The compiled, static snapshot of the Public Suffix List.
+
+use crate::matcher::{self, Match, Matcher, Rule};
+use crate::Type;
+
+/// A compiled, static snapshot of the Public Suffix List.
+///
+/// ```
+/// use psl::{List, parser::DomainName};
+///
+/// let domain = List.parse_domain_name("example.com").unwrap();
+/// assert_eq!(domain.suffix(), "com");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct List;
+
+// A small, representative slice of the official list, split the same way
+// the upstream `public_suffix_list.dat` is: ICANN domains first, then the
+// `===BEGIN PRIVATE DOMAINS===` section.
+static RULES: &[Rule] = &[
+    // ICANN DOMAINS
+    Rule {
+        suffix: "com",
+        typ: Type::Icann,
+    },
+    Rule {
+        suffix: "net",
+        typ: Type::Icann,
+    },
+    Rule {
+        suffix: "org",
+        typ: Type::Icann,
+    },
+    Rule {
+        suffix: "uk",
+        typ: Type::Icann,
+    },
+    Rule {
+        suffix: "co.uk",
+        typ: Type::Icann,
+    },
+    Rule {
+        suffix: "cn",
+        typ: Type::Icann,
+    },
+    Rule {
+        suffix: "xn--55qx5d.cn",
+        typ: Type::Icann,
+    },
+    Rule {
+        suffix: "中国",
+        typ: Type::Icann,
+    },
+    // PRIVATE DOMAINS
+    Rule {
+        suffix: "uk.com",
+        typ: Type::Private,
+    },
+    Rule {
+        suffix: "github.io",
+        typ: Type::Private,
+    },
+    Rule {
+        suffix: "s3.amazonaws.com",
+        typ: Type::Private,
+    },
+];
+
+impl Matcher for List {
+    fn find(&self, domain: &str) -> Option<Match> {
+        matcher::find(RULES, domain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::DomainName;
+    use crate::{List, Type};
+
+    #[test]
+    fn tags_icann_and_private_suffixes() {
+        let domain = List.parse_domain_name("example.com").unwrap();
+        assert_eq!(domain.suffix().typ(), Some(Type::Icann));
+
+        let domain = List.parse_domain_name("example.github.io").unwrap();
+        assert_eq!(domain.suffix().typ(), Some(Type::Private));
+    }
+}
@@ -0,0 +1,73 @@
+//! The suffix-matching engine shared by every type that implements
+//! [`parser::DomainName`](crate::parser::DomainName) or
+//! [`parser::DnsName`](crate::parser::DnsName).
+
+use crate::Type;
+
+use alloc::{string::String, vec::Vec};
+
+/// A single compiled rule together with the PSL section it was sourced
+/// from.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Rule {
+    pub suffix: &'static str,
+    pub typ: Type,
+}
+
+/// The result of a successful suffix lookup.
+pub(crate) struct Match {
+    /// The number of labels the matched suffix occupies.
+    pub len: usize,
+    pub typ: Type,
+}
+
+/// Anything that can answer "what suffix (if any) matches this domain?".
+///
+/// `domain` is expected to already be lower-cased and free of a trailing
+/// `.`. Implementors only need to find the longest rule that matches;
+/// [`parser`](crate::parser) takes care of turning that into a
+/// [`Suffix`](crate::Suffix).
+pub(crate) trait Matcher {
+    fn find(&self, domain: &str) -> Option<Match>;
+}
+
+/// Finds the longest rule in `rules` that matches a suffix of `domain`,
+/// trying the whole name first and then progressively shorter suffixes so
+/// the most specific rule always wins.
+pub(crate) fn find(rules: &[Rule], domain: &str) -> Option<Match> {
+    let labels: Vec<&str> = domain.split('.').collect();
+    for start in 0..labels.len() {
+        let candidate: String = labels[start..].join(".");
+        if let Some(rule) = rules.iter().find(|rule| rule.suffix == candidate) {
+            return Some(Match {
+                len: labels.len() - start,
+                typ: rule.typ,
+            });
+        }
+    }
+    None
+}
+
+/// Returns the last `n` labels of `name`, as a slice of the original
+/// string (so any trailing `.` on an absolute DNS name is preserved).
+pub(crate) fn label_suffix(name: &str, n: usize) -> &str {
+    if n == 0 {
+        return "";
+    }
+    let trimmed = name.strip_suffix('.').unwrap_or(name);
+    let mut seen = 0;
+    for (i, c) in trimmed.char_indices().rev() {
+        if c == '.' {
+            seen += 1;
+            if seen == n {
+                return &name[i + 1..];
+            }
+        }
+    }
+    name
+}
+
+/// Counts the labels in `name`, ignoring a trailing `.` if present.
+pub(crate) fn label_count(name: &str) -> usize {
+    name.strip_suffix('.').unwrap_or(name).split('.').count()
+}
@@ -0,0 +1,336 @@
+//! Traits for parsing names against anything that can answer suffix
+//! queries, such as [`List`](crate::List).
+
+use crate::matcher::{self, Matcher};
+use crate::{dns, domain, Error, ErrorKind, Result, Suffix};
+
+use alloc::{string::String, vec::Vec};
+
+/// Parses domain names, i.e. RFC 1123 hostnames.
+///
+/// Every character in the name must be alphanumeric or a hyphen, and no
+/// label may start or end with a hyphen. See [`ErrorKind`] for the full set
+/// of validation failures.
+pub trait DomainName {
+    /// Parses `name` as a domain name, returning its root and suffix.
+    fn parse_domain_name<'a>(&self, name: &'a str) -> Result<'a, domain::Name<'a>>;
+
+    /// Parses `name` as a domain name without validating that it is a
+    /// syntactically legal RFC 1123 hostname.
+    ///
+    /// Length limits (name, label and label-count) are still enforced, but
+    /// the character-set and label-shape checks that produce
+    /// [`ErrorKind::IllegalCharacter`], [`ErrorKind::LabelStartNotAlnum`],
+    /// [`ErrorKind::LabelEndNotAlnum`] and [`ErrorKind::NumericTld`] are
+    /// skipped, so the root and suffix of otherwise-invalid input (IP
+    /// addresses, underscored labels, junk strings) can still be computed
+    /// purely from the PSL rules.
+    fn parse_domain_name_lax<'a>(&self, name: &'a str) -> Result<'a, domain::Name<'a>>;
+}
+
+/// Parses DNS names.
+///
+/// DNS names may be fully qualified (end in a `.`) and, unlike domain
+/// names, may contain any octet in a label.
+pub trait DnsName {
+    /// Parses `name` as a DNS name.
+    fn parse_dns_name<'a>(&self, name: &'a str) -> Result<'a, dns::Name<'a>>;
+}
+
+impl<T: Matcher> DomainName for T {
+    fn parse_domain_name<'a>(&self, name: &'a str) -> Result<'a, domain::Name<'a>> {
+        validate(name)?;
+        Ok(self.domain_name(name))
+    }
+
+    fn parse_domain_name_lax<'a>(&self, name: &'a str) -> Result<'a, domain::Name<'a>> {
+        validate_lengths(name)?;
+        Ok(self.domain_name(name))
+    }
+}
+
+trait DomainNameMatcher: Matcher {
+    /// Builds a `domain::Name` purely from suffix matching; the caller is
+    /// responsible for validating `name` first.
+    fn domain_name<'a>(&self, name: &'a str) -> domain::Name<'a> {
+        let lowered = name.to_ascii_lowercase();
+        let found = self.find(&lowered);
+        let suffix_len = found.as_ref().map_or(1, |m| m.len);
+        let typ = found.map(|m| m.typ);
+        let suffix = Suffix::new(matcher::label_suffix(name, suffix_len), typ);
+        domain::Name::new(name, suffix, suffix_len)
+    }
+}
+
+impl<T: Matcher> DomainNameMatcher for T {}
+
+impl<T: Matcher> DnsName for T {
+    fn parse_dns_name<'a>(&self, name: &'a str) -> Result<'a, dns::Name<'a>> {
+        if name.is_empty() {
+            return Err(Error::new(ErrorKind::EmptyName, name));
+        }
+        if name == "." {
+            // The root name has no labels and therefore no suffix.
+            return Ok(dns::Name::new(name, None));
+        }
+
+        let mut labels = decode_dns_labels(name)?;
+        // A trailing, unescaped `.` decodes to an empty final label; that
+        // marks the name as fully qualified rather than as an empty label.
+        if labels
+            .last()
+            .is_some_and(|l| l.raw_start == l.raw_end && l.decoded.is_empty())
+        {
+            labels.pop();
+        }
+
+        let mut decoded_len = labels.len().saturating_sub(1); // the dots between labels
+        for label in &labels {
+            if label.decoded.is_empty() {
+                return Err(Error::at(ErrorKind::EmptyLabel, name, label.raw_start));
+            }
+            if label.byte_len > 63 {
+                return Err(Error::at(ErrorKind::LabelTooLong, name, label.raw_start));
+            }
+            decoded_len += label.byte_len;
+        }
+        if decoded_len > 255 {
+            return Err(Error::new(ErrorKind::NameTooLong, name));
+        }
+
+        // No suffix rule has a label containing a literal `.`, so only the
+        // labels to the right of the last one holding an escaped `.` are
+        // matched; re-joining that label with `.` would split it in two.
+        let first = labels
+            .iter()
+            .rposition(|l| l.decoded.contains('.'))
+            .map_or(0, |i| i + 1);
+        let lowered: Vec<String> = labels[first..]
+            .iter()
+            .map(|l| l.decoded.to_ascii_lowercase())
+            .collect();
+        let joined = lowered.join(".");
+        let suffix = self.find(&joined).filter(|m| m.len <= labels.len() - first).map(|m| {
+            let start = labels[labels.len() - m.len].raw_start;
+            Suffix::new(&name[start..], Some(m.typ))
+        });
+        Ok(dns::Name::new(name, suffix))
+    }
+}
+
+/// A single DNS label after RFC 4343 escape decoding.
+struct DecodedLabel {
+    /// Byte offset of the label, before decoding, in the original input.
+    raw_start: usize,
+    /// Byte offset just past the label, before decoding, in the original
+    /// input.
+    raw_end: usize,
+    /// The label's decoded content: `\DDD` resolved to the byte it encodes
+    /// and `\X` resolved to a literal `X`. Used for suffix matching only;
+    /// a `\DDD` escape is stored here as the `char` of that code point,
+    /// which does not necessarily re-encode to the one byte it represents,
+    /// so length limits must use `byte_len` instead of `decoded.len()`.
+    decoded: String,
+    /// The number of actual decoded bytes this label represents: each
+    /// `\DDD` escape counts as 1, everything else counts as its UTF-8
+    /// length.
+    byte_len: usize,
+}
+
+/// Splits `name` into labels the way RFC 4343 zone-file parsers do: a
+/// backslash followed by three decimal digits decodes to the byte they
+/// represent, a backslash followed by anything else is that character
+/// taken literally (including `.`, which does not then end the label), and
+/// any other unescaped `.` separates labels.
+fn decode_dns_labels(name: &str) -> Result<'_, Vec<DecodedLabel>> {
+    let mut labels = Vec::new();
+    let mut chars = name.char_indices().peekable();
+    let mut label_start = 0;
+    let mut decoded = String::new();
+    let mut byte_len = 0;
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '.' => {
+                labels.push(DecodedLabel {
+                    raw_start: label_start,
+                    raw_end: i,
+                    decoded: core::mem::take(&mut decoded),
+                    byte_len: core::mem::take(&mut byte_len),
+                });
+                label_start = i + 1;
+            }
+            '\\' => {
+                let digits: Vec<(usize, char)> = chars.clone().take(3).collect();
+                if digits.len() == 3 && digits.iter().all(|&(_, d)| d.is_ascii_digit()) {
+                    let value = digits
+                        .iter()
+                        .fold(0u16, |acc, &(_, d)| acc * 10 + (d as u8 - b'0') as u16);
+                    if value > 255 {
+                        return Err(Error::at(ErrorKind::IllegalCharacter, name, label_start));
+                    }
+                    // The escaped value is one raw byte, even though the
+                    // `char` we store it as for matching purposes may
+                    // re-encode to more than one UTF-8 byte.
+                    decoded.push(value as u8 as char);
+                    byte_len += 1;
+                    for _ in 0..3 {
+                        chars.next();
+                    }
+                } else if let Some(&(_, next)) = chars.peek() {
+                    decoded.push(next);
+                    byte_len += next.len_utf8();
+                    chars.next();
+                } else {
+                    return Err(Error::at(ErrorKind::IllegalCharacter, name, label_start));
+                }
+            }
+            other => {
+                decoded.push(other);
+                byte_len += other.len_utf8();
+            }
+        }
+    }
+    labels.push(DecodedLabel {
+        raw_start: label_start,
+        raw_end: name.len(),
+        decoded,
+        byte_len,
+    });
+    Ok(labels)
+}
+
+fn validate(name: &str) -> Result<'_, ()> {
+    let labels = validate_lengths(name)?;
+    for &(offset, label) in &labels {
+        validate_label(name, offset, label)?;
+    }
+    if let Some(&(_offset, tld)) = labels.last() {
+        if !tld.is_empty() && tld.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Error::new(ErrorKind::NumericTld, name));
+        }
+        #[cfg(feature = "strict-tld")]
+        validate_strict_tld(name, _offset, tld)?;
+    }
+    Ok(())
+}
+
+/// Enforces that the TLD is either entirely ASCII letters or a valid
+/// A-label (at least five characters, starting with `xn--`), for callers
+/// that want RFC 1123 hostname validation to also reject TLDs that merely
+/// happen not to appear in the suffix list.
+#[cfg(feature = "strict-tld")]
+fn validate_strict_tld<'a>(name: &'a str, offset: usize, tld: &str) -> Result<'a, ()> {
+    let is_alphabetic = !tld.is_empty() && tld.chars().all(|c| c.is_ascii_alphabetic());
+    let is_a_label = tld.len() >= 5 && tld.starts_with("xn--");
+    if is_alphabetic || is_a_label {
+        Ok(())
+    } else {
+        Err(Error::at(ErrorKind::InvalidTld, name, offset))
+    }
+}
+
+/// Enforces the length limits that apply regardless of parsing mode,
+/// returning each label together with its byte offset so the caller can
+/// layer further validation (and precise error reporting) on top.
+fn validate_lengths(name: &str) -> Result<'_, Vec<(usize, &str)>> {
+    if name.is_empty() {
+        return Err(Error::new(ErrorKind::EmptyName, name));
+    }
+    if name.len() > 253 {
+        return Err(Error::new(ErrorKind::NameTooLong, name));
+    }
+    let labels: Vec<(usize, &str)> = labels_with_offsets(name).collect();
+    if labels.len() > 127 {
+        return Err(Error::new(ErrorKind::TooManyLabels, name));
+    }
+    for &(offset, label) in &labels {
+        if label.is_empty() {
+            return Err(Error::at(ErrorKind::EmptyLabel, name, offset));
+        }
+        if label.len() > 63 {
+            return Err(Error::at(ErrorKind::LabelTooLong, name, offset));
+        }
+    }
+    Ok(labels)
+}
+
+fn validate_label<'a>(name: &'a str, offset: usize, label: &str) -> Result<'a, ()> {
+    if !label.chars().all(|c| c.is_alphanumeric() || c == '-') {
+        return Err(Error::at(ErrorKind::IllegalCharacter, name, offset));
+    }
+    if !label.chars().next().is_some_and(char::is_alphanumeric) {
+        return Err(Error::at(ErrorKind::LabelStartNotAlnum, name, offset));
+    }
+    if !label.chars().next_back().is_some_and(char::is_alphanumeric) {
+        return Err(Error::at(ErrorKind::LabelEndNotAlnum, name, offset));
+    }
+    Ok(())
+}
+
+/// Splits `name` on every `.`, pairing each label with its
+/// byte offset into `name`.
+fn labels_with_offsets(name: &str) -> impl Iterator<Item = (usize, &str)> + '_ {
+    let mut offset = 0;
+    name.split('.').map(move |label| {
+        let this = offset;
+        offset += label.len() + 1;
+        (this, label)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::List;
+
+    #[test]
+    fn escaped_dot_is_kept_within_one_label() {
+        let labels = decode_dns_labels(r"foo\.bar.example.com").unwrap();
+        let decoded: Vec<&str> = labels.iter().map(|l| l.decoded.as_str()).collect();
+        assert_eq!(decoded, ["foo.bar", "example", "com"]);
+
+        let name = List.parse_dns_name(r"foo\.bar.example.com").unwrap();
+        assert_eq!(name.suffix().unwrap(), "com");
+    }
+
+    #[test]
+    fn decimal_escape_decodes_to_one_byte() {
+        let labels = decode_dns_labels(r"a\098.com").unwrap();
+        assert_eq!(labels[0].decoded, "ab");
+    }
+
+    #[test]
+    fn trailing_unescaped_dot_marks_fqdn() {
+        let name = List.parse_dns_name("example.com.").unwrap();
+        assert!(name.is_fqdn());
+        assert_eq!(name.suffix().unwrap(), "com.");
+    }
+
+    #[test]
+    fn high_byte_escapes_are_measured_in_decoded_bytes() {
+        // Each `\200` escape is one raw byte, even though, stored as a
+        // `char` for matching, it re-encodes to two UTF-8 bytes. A label
+        // of 63 such escapes is exactly at the 63-byte limit and must be
+        // accepted; measuring the re-encoded `String` instead would see
+        // 126 bytes and wrongly reject it.
+        let label = "\\200".repeat(63);
+        let name = alloc::format!("{}.com.", label);
+        let parsed = List.parse_dns_name(&name).unwrap();
+        assert_eq!(parsed.suffix().unwrap(), "com.");
+
+        // One more escape pushes the label over the limit.
+        let too_long = alloc::format!("{}\\200.com.", label);
+        let err = List.parse_dns_name(&too_long).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::LabelTooLong);
+    }
+
+    #[test]
+    #[cfg(feature = "strict-tld")]
+    fn strict_tld_rejects_non_alphabetic_non_a_label_tlds() {
+        let err = List.parse_domain_name("example.c0m").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidTld);
+
+        assert!(List.parse_domain_name("example.com").is_ok());
+        assert!(List.parse_domain_name("example.xn--55qx5d").is_ok());
+    }
+}